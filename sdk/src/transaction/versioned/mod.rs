@@ -4,8 +4,11 @@
 
 use {
     crate::{
+        feature_set::FeatureSet,
         hash::Hash,
         message::VersionedMessage,
+        precompiles::get_precompiles,
+        pubkey::Pubkey,
         sanitize::SanitizeError,
         short_vec,
         signature::Signature,
@@ -66,14 +69,9 @@ impl VersionedTransaction {
         message: VersionedMessage,
         keypairs: &T,
     ) -> std::result::Result<Self, SignerError> {
-        let static_account_keys = message.static_account_keys();
-        if static_account_keys.len() < message.header().num_required_signatures as usize {
-            return Err(SignerError::InvalidInput("invalid message".to_string()));
-        }
+        let expected_signer_keys = Self::expected_signer_keys(&message)?;
 
         let signer_keys = keypairs.try_pubkeys()?;
-        let expected_signer_keys =
-            &static_account_keys[0..message.header().num_required_signatures as usize];
 
         match signer_keys.len().cmp(&expected_signer_keys.len()) {
             Ordering::Greater => Err(SignerError::TooManySigners),
@@ -109,6 +107,73 @@ impl VersionedTransaction {
         })
     }
 
+    /// Create an unsigned transaction from a versioned message.
+    pub fn new_unsigned(message: VersionedMessage) -> Self {
+        let num_required_signatures = usize::from(message.header().num_required_signatures);
+        Self {
+            signatures: vec![Signature::default(); num_required_signatures],
+            message,
+        }
+    }
+
+    /// Returns the position of each pubkey within the transaction's expected
+    /// signers, or `None` if a pubkey is not an expected signer.
+    pub fn get_signing_keypair_positions(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> std::result::Result<Vec<Option<usize>>, SignerError> {
+        let expected_signer_keys = Self::expected_signer_keys(&self.message)?;
+
+        Ok(pubkeys
+            .iter()
+            .map(|pubkey| expected_signer_keys.iter().position(|x| x == pubkey))
+            .collect())
+    }
+
+    /// Returns the prefix of `message`'s static account keys that must sign
+    /// the transaction, i.e. `static_account_keys[0..num_required_signatures]`.
+    fn expected_signer_keys(
+        message: &VersionedMessage,
+    ) -> std::result::Result<&[Pubkey], SignerError> {
+        let static_account_keys = message.static_account_keys();
+        let num_required_signatures = message.header().num_required_signatures as usize;
+        if static_account_keys.len() < num_required_signatures {
+            return Err(SignerError::InvalidInput("invalid message".to_string()));
+        }
+
+        Ok(&static_account_keys[0..num_required_signatures])
+    }
+
+    /// Signs the transaction with `keypairs`, filling in only the signature slots they own.
+    pub fn partial_sign<T: Signers>(
+        &mut self,
+        keypairs: &T,
+    ) -> std::result::Result<(), SignerError> {
+        let positions = self
+            .get_signing_keypair_positions(&keypairs.try_pubkeys()?)?
+            .into_iter()
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(SignerError::KeypairPubkeyMismatch)?;
+
+        let message_data = self.message.serialize();
+        let signatures = keypairs.try_sign_message(&message_data)?;
+        for (position, signature) in positions.into_iter().zip(signatures) {
+            self.signatures[position] = signature;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of signature slots in the transaction.
+    pub fn signature_count(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Returns `true` if every signature slot has been filled in, i.e. no
+    /// [`Signature::default()`] placeholders remain.
+    pub fn is_signed(&self) -> bool {
+        self.signatures.iter().all(|sig| *sig != Signature::default())
+    }
+
     pub fn sanitize(
         &self,
         require_static_program_ids: bool,
@@ -168,19 +233,98 @@ impl VersionedTransaction {
         }
     }
 
+    /// Verifies the ed25519 and secp256k1 precompile instructions carried by the message.
+    pub fn verify_precompiles(&self, feature_set: &FeatureSet) -> Result<()> {
+        let account_keys = self.message.static_account_keys();
+        let instructions = self.message.instructions();
+        let instruction_datas: Vec<&[u8]> =
+            instructions.iter().map(|ix| ix.data.as_slice()).collect();
+
+        for instruction in instructions {
+            let program_id = account_keys
+                .get(instruction.program_id_index as usize)
+                .ok_or(TransactionError::SignatureFailure)?;
+
+            for precompile in get_precompiles() {
+                if precompile.check_id(program_id) {
+                    precompile
+                        .verify(&instruction.data, &instruction_datas, feature_set)
+                        .map_err(|_| TransactionError::SignatureFailure)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Verify the transaction and return a list of verification results
     pub fn verify_with_results(&self) -> Vec<bool> {
         let message_bytes = self.message.serialize();
         self._verify_with_results(&message_bytes)
     }
 
+    /// Verifies `self.signatures` against `message_bytes`, batching when there's more than one.
     fn _verify_with_results(&self, message_bytes: &[u8]) -> Vec<bool> {
+        let pubkeys = self.message.static_account_keys();
+
+        // Only the first `signatures.len()` static account keys are ever
+        // checked below (via `zip`) — `static_account_keys()` typically also
+        // contains non-signer accounts (the invoked program, a recipient, a
+        // sysvar, etc.), so the signer prefix must be sliced out explicitly
+        // rather than requiring the two lengths to match exactly.
+        if self.signatures.len() > 1
+            && self.signatures.len() <= pubkeys.len()
+            && Self::batch_verify(
+                &self.signatures,
+                &pubkeys[..self.signatures.len()],
+                message_bytes,
+            )
+        {
+            return vec![true; self.signatures.len()];
+        }
+
         self.signatures
             .iter()
-            .zip(self.message.static_account_keys().iter())
-            .map(|(signature, pubkey)| signature.verify(pubkey.as_ref(), message_bytes))
+            .zip(pubkeys.iter())
+            .map(|(signature, pubkey)| Self::verify_strict(signature, pubkey, message_bytes))
             .collect()
     }
+
+    /// Verifies all `(signature, pubkey)` pairs against `message_bytes` with one batched check.
+    fn batch_verify(signatures: &[Signature], pubkeys: &[Pubkey], message_bytes: &[u8]) -> bool {
+        if signatures.is_empty() || signatures.len() != pubkeys.len() {
+            return false;
+        }
+
+        let messages = vec![message_bytes; signatures.len()];
+        let dalek_signatures: std::result::Result<Vec<_>, _> = signatures
+            .iter()
+            .map(|signature| ed25519_dalek::Signature::try_from(signature.as_ref()))
+            .collect();
+        let dalek_pubkeys: std::result::Result<Vec<_>, _> = pubkeys
+            .iter()
+            .map(|pubkey| ed25519_dalek::PublicKey::from_bytes(pubkey.as_ref()))
+            .collect();
+
+        match (dalek_signatures, dalek_pubkeys) {
+            (Ok(signatures), Ok(pubkeys)) => {
+                ed25519_dalek::verify_batch(&messages, &signatures, &pubkeys).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// Verifies one `(signature, pubkey)` pair with the same check `batch_verify` relies on.
+    fn verify_strict(signature: &Signature, pubkey: &Pubkey, message_bytes: &[u8]) -> bool {
+        let (Ok(signature), Ok(pubkey)) = (
+            ed25519_dalek::Signature::try_from(signature.as_ref()),
+            ed25519_dalek::PublicKey::from_bytes(pubkey.as_ref()),
+        ) else {
+            return false;
+        };
+
+        pubkey.verify_strict(message_bytes, &signature).is_ok()
+    }
 }
 
 #[cfg(test)]
@@ -188,6 +332,7 @@ mod tests {
     use {
         super::*,
         crate::{
+            ed25519_program,
             message::Message as LegacyMessage,
             signer::{keypair::Keypair, Signer},
         },
@@ -240,4 +385,180 @@ mod tests {
             Err(err) => assert_eq!(Some(err), None),
         }
     }
+
+    #[test]
+    fn test_partial_sign() {
+        let keypair0 = Keypair::new();
+        let keypair1 = Keypair::new();
+        let keypair2 = Keypair::new();
+
+        let message = VersionedMessage::Legacy(LegacyMessage::new(
+            &[Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                vec![
+                    AccountMeta::new_readonly(keypair1.pubkey(), true),
+                    AccountMeta::new_readonly(keypair2.pubkey(), false),
+                ],
+            )],
+            Some(&keypair0.pubkey()),
+        ));
+
+        let mut tx = VersionedTransaction::new_unsigned(message);
+        assert_eq!(tx.signature_count(), 2);
+        assert!(!tx.is_signed());
+
+        assert_eq!(
+            tx.get_signing_keypair_positions(&[keypair0.pubkey(), keypair1.pubkey()]),
+            Ok(vec![Some(0), Some(1)])
+        );
+        assert_eq!(
+            tx.get_signing_keypair_positions(&[keypair2.pubkey()]),
+            Ok(vec![None])
+        );
+
+        tx.partial_sign(&[&keypair1]).unwrap();
+        assert!(!tx.is_signed());
+        assert_ne!(tx.signatures[1], Signature::default());
+        assert_eq!(tx.signatures[0], Signature::default());
+
+        assert_eq!(
+            tx.partial_sign(&[&keypair2]),
+            Err(SignerError::KeypairPubkeyMismatch)
+        );
+
+        tx.partial_sign(&[&keypair0]).unwrap();
+        assert!(tx.is_signed());
+        assert_eq!(tx.verify_with_results(), vec![true; 2]);
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let keypair0 = Keypair::new();
+        let keypair1 = Keypair::new();
+        let keypair2 = Keypair::new();
+
+        let message = VersionedMessage::Legacy(LegacyMessage::new(
+            &[Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                vec![
+                    AccountMeta::new_readonly(keypair1.pubkey(), true),
+                    AccountMeta::new_readonly(keypair2.pubkey(), true),
+                    AccountMeta::new_readonly(Pubkey::new_unique(), false),
+                ],
+            )],
+            Some(&keypair0.pubkey()),
+        ));
+
+        // 3 required signatures, but `static_account_keys()` also carries
+        // the invoked program id and a non-signer account, so this message
+        // exercises the `signatures.len() <= pubkeys.len()` (not `==`)
+        // batch-verify guard the way a real transaction would.
+        assert!(message.static_account_keys().len() > 3);
+
+        let tx =
+            VersionedTransaction::try_new(message, &[&keypair0, &keypair1, &keypair2]).unwrap();
+        assert_eq!(tx.verify_with_results(), vec![true; 3]);
+
+        // Corrupting one signature must not corrupt the others: the batch
+        // path should fail closed and the per-signature fallback should
+        // still pinpoint exactly the forged slot.
+        let forged_index = 1;
+        let mut forged = tx;
+        forged.signatures[forged_index] = Signature::default();
+        let results = forged.verify_with_results();
+        assert_eq!(results.len(), 3);
+        assert!(!results[forged_index]);
+        assert_eq!(results.iter().filter(|verified| **verified).count(), 2);
+    }
+
+    #[test]
+    fn test_batch_verify_agrees_with_single_signature_fallback() {
+        // The curve's identity point is a degenerate, small-order "public
+        // key" that a correct cofactored check (verify_batch / verify_strict)
+        // and a cofactorless one can disagree on, so it's a meaningful probe
+        // of whether the batch path and the fallback path give the same
+        // answer rather than merely both saying "false" for unrelated
+        // reasons.
+        let mut weak_pubkey_bytes = [0u8; 32];
+        weak_pubkey_bytes[0] = 1;
+        let weak_pubkey = Pubkey::new_from_array(weak_pubkey_bytes);
+        let zero_signature = Signature::new(&[0u8; 64]);
+        let message_bytes = b"batch/single verification equivalence";
+
+        let signatures = [zero_signature, zero_signature];
+        let pubkeys = [weak_pubkey, weak_pubkey];
+
+        let batch_result = VersionedTransaction::batch_verify(&signatures, &pubkeys, message_bytes);
+        let fallback_results: Vec<bool> = signatures
+            .iter()
+            .zip(pubkeys.iter())
+            .map(|(signature, pubkey)| {
+                VersionedTransaction::verify_strict(signature, pubkey, message_bytes)
+            })
+            .collect();
+
+        assert_eq!(batch_result, fallback_results.iter().all(|result| *result));
+    }
+
+    #[test]
+    fn test_verify_precompiles_no_precompile_instructions() {
+        let keypair = Keypair::new();
+        let message = VersionedMessage::Legacy(LegacyMessage::new(
+            &[Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![])],
+            Some(&keypair.pubkey()),
+        ));
+        let tx = VersionedTransaction::try_new(message, &[&keypair]).unwrap();
+        assert_eq!(tx.verify_precompiles(&FeatureSet::all_enabled()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_precompiles_ed25519_instruction() {
+        let keypair = Keypair::new();
+        let message_bytes = b"precompile test message";
+        let signature = keypair.sign_message(message_bytes);
+
+        const DATA_START: u16 = 2 + 14;
+        let public_key_offset = DATA_START;
+        let signature_offset = DATA_START + 32;
+        let message_data_offset = DATA_START + 32 + 64;
+
+        let mut data = vec![1u8, 0u8];
+        data.extend_from_slice(&signature_offset.to_le_bytes());
+        data.extend_from_slice(&u16::MAX.to_le_bytes());
+        data.extend_from_slice(&public_key_offset.to_le_bytes());
+        data.extend_from_slice(&u16::MAX.to_le_bytes());
+        data.extend_from_slice(&message_data_offset.to_le_bytes());
+        data.extend_from_slice(&(message_bytes.len() as u16).to_le_bytes());
+        data.extend_from_slice(&u16::MAX.to_le_bytes());
+        data.extend_from_slice(keypair.pubkey().as_ref());
+        data.extend_from_slice(signature.as_ref());
+        data.extend_from_slice(message_bytes);
+
+        let message = VersionedMessage::Legacy(LegacyMessage::new(
+            &[Instruction::new_with_bytes(ed25519_program::id(), &data, vec![])],
+            Some(&keypair.pubkey()),
+        ));
+        let tx = VersionedTransaction::try_new(message, &[&keypair]).unwrap();
+        assert_eq!(tx.verify_precompiles(&FeatureSet::all_enabled()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_precompiles_out_of_range_program_id_index() {
+        let keypair = Keypair::new();
+        let mut message = LegacyMessage::new(
+            &[Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![])],
+            Some(&keypair.pubkey()),
+        );
+        message.instructions[0].program_id_index = u8::MAX;
+
+        let tx =
+            VersionedTransaction::try_new(VersionedMessage::Legacy(message), &[&keypair]).unwrap();
+
+        assert_eq!(
+            tx.verify_precompiles(&FeatureSet::all_enabled()),
+            Err(TransactionError::SignatureFailure)
+        );
+    }
 }